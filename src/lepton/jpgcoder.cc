@@ -4688,8 +4688,8 @@ bool parse_jfif_jpg( unsigned char type, unsigned int len, unsigned int alloc_le
             imgwidth  = B_SHORT( hpos + 3 < alloc_len ?segment[ hpos + 3 ]:0, hpos + 4 < alloc_len ?segment[ hpos + 4 ]:0 );
             cmpc      = hpos + 5 < alloc_len ?  segment[ hpos + 5 ]:0;
             if ( cmpc > 4 ) {
-                cmpc = 4;
                 fprintf( stderr, "image has %i components, max 4 are supported", cmpc );
+                cmpc = 4;
                 errorlevel.store(2);
                 return false;
             }